@@ -1,6 +1,6 @@
 //! Monitor an SHTC3 sensor on Linux in the terminal.
 
-use linux_embedded_hal::{Delay, I2cdev};
+use linux_embedded_hal::I2cdev;
 use shtcx::{self, Measurement, PowerMode};
 use smol::channel::{Receiver, Sender};
 use std::time::Duration;
@@ -43,12 +43,12 @@ async fn poll(sender: Sender<(Measurement, Measurement)>) -> Result<(), smol::ch
     // Initialize sensor driver
     let dev = I2cdev::new(DEVICE).unwrap();
     let mut sht = shtcx::shtc3(dev);
-    let mut delay = Delay;
 
     loop {
-        // Do measurements
-        let normal = sht.measure(PowerMode::NormalMode, &mut delay).unwrap();
-        let lowpwr = sht.measure(PowerMode::LowPower, &mut delay).unwrap();
+        // Do measurements, yielding to the executor instead of blocking it
+        // while the sensor converts.
+        let normal = measure(&mut sht, PowerMode::NormalMode).await;
+        let lowpwr = measure(&mut sht, PowerMode::LowPower).await;
 
         // Send measurements over
         let _ = sender.send((normal, lowpwr)).await;
@@ -56,3 +56,17 @@ async fn poll(sender: Sender<(Measurement, Measurement)>) -> Result<(), smol::ch
         smol::Timer::after(SENSOR_REFRESH_DELAY).await;
     }
 }
+
+/// Drive a split measurement with a non-blocking timer instead of the
+/// blocking `measure()` convenience method.
+async fn measure<I2C>(sht: &mut shtcx::ShtCx<I2C, shtcx::Shtc3>, mode: PowerMode) -> Measurement
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    sht.start_measurement(mode).unwrap();
+    smol::Timer::after(Duration::from_micros(u64::from(
+        sht.max_conversion_time_us(mode),
+    )))
+    .await;
+    sht.read_measurement().unwrap()
+}
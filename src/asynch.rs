@@ -0,0 +1,148 @@
+//! Async driver variant, built on `embedded-hal-async`.
+//!
+//! Mirrors the `wakeup`, `sleep`, `reset`, `device_identifier`, `measure`
+//! and `measure_raw` methods of the blocking [`ShtCx`](crate::ShtCx), but
+//! awaits the conversion delay instead of busy-blocking it. The split
+//! start/read measurement and clock-stretching methods are not provided. This lets an async
+//! executor run other tasks while a measurement is in flight, rather than
+//! stalling the whole reactor for the ~12 ms (normal) or ~0.8 ms
+//! (low-power) conversion time.
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::I2c;
+
+use crate::commands::Command;
+use crate::measurement::RawMeasurement;
+use crate::model::ShtCxModel;
+use crate::{verify_crc, Error, Measurement, PowerMode, Shtc1, Shtc3, I2C_ADDRESS};
+
+/// Async driver for the Sensirion SHTC1 / SHTC3 humidity and temperature
+/// sensors.
+///
+/// Use the [`shtc1_async()`](fn.shtc1_async.html) or
+/// [`shtc3_async()`](fn.shtc3_async.html) constructor functions to create
+/// an instance for your sensor variant.
+#[derive(Debug)]
+pub struct AsyncShtCx<I2C, M> {
+    i2c: I2C,
+    address: u8,
+    _model: core::marker::PhantomData<M>,
+}
+
+/// Create a new async driver instance for the SHTC1.
+pub fn shtc1_async<I2C>(i2c: I2C) -> AsyncShtCx<I2C, Shtc1> {
+    AsyncShtCx {
+        i2c,
+        address: I2C_ADDRESS,
+        _model: core::marker::PhantomData,
+    }
+}
+
+/// Create a new async driver instance for the SHTC3.
+pub fn shtc3_async<I2C>(i2c: I2C) -> AsyncShtCx<I2C, Shtc3> {
+    AsyncShtCx {
+        i2c,
+        address: I2C_ADDRESS,
+        _model: core::marker::PhantomData,
+    }
+}
+
+impl<I2C, M> AsyncShtCx<I2C, M>
+where
+    I2C: I2c,
+    M: ShtCxModel,
+{
+    /// Release the underlying I2C bus instance.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    async fn send_command(&mut self, command: Command) -> Result<(), Error<I2C::Error>> {
+        self.i2c
+            .write(self.address, &command.to_be_bytes())
+            .await
+            .map_err(Error::I2c)
+    }
+
+    /// Wake up the sensor from sleep mode.
+    ///
+    /// The SHTC3 automatically goes to sleep after each measurement. This
+    /// command must be sent (and the [recommended wakeup
+    /// time](https://www.sensirion.com/file/datasheet_shtc3) observed)
+    /// before a new measurement can be taken.
+    pub async fn wakeup<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::Wakeup).await?;
+        delay.delay_us(240).await;
+        Ok(())
+    }
+
+    /// Put the sensor into sleep mode to save power between measurements.
+    pub async fn sleep(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::Sleep).await
+    }
+
+    /// Perform a soft reset of the sensor.
+    pub async fn reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::SoftReset).await?;
+        delay.delay_us(240).await;
+        Ok(())
+    }
+
+    /// Read the device identifier register.
+    pub async fn device_identifier(&mut self) -> Result<u16, Error<I2C::Error>> {
+        let mut buf = [0; 3];
+        self.i2c
+            .write_read(self.address, &Command::ReadIdReg.to_be_bytes(), &mut buf)
+            .await
+            .map_err(Error::I2c)?;
+        let word = [buf[0], buf[1]];
+        if !verify_crc(&word, buf[2]) {
+            return Err(Error::Crc);
+        }
+        Ok(u16::from_be_bytes(word))
+    }
+
+    /// Perform a single temperature and humidity measurement.
+    ///
+    /// This awaits `delay` for the conversion time required by the given
+    /// [`PowerMode`](crate::PowerMode) (roughly 12 ms in normal mode,
+    /// 0.8 ms in low power mode on the SHTC3), yielding to the executor
+    /// instead of blocking it. Each word's CRC8 checksum is validated,
+    /// returning [`Error::Crc`](crate::Error::Crc) on mismatch.
+    pub async fn measure<D: DelayNs>(
+        &mut self,
+        mode: PowerMode,
+        delay: &mut D,
+    ) -> Result<Measurement, Error<I2C::Error>> {
+        self.measure_raw(mode, delay)
+            .await?
+            .validate()
+            .ok_or(Error::Crc)
+    }
+
+    /// Perform a single temperature and humidity measurement, without
+    /// validating the CRC8 checksums.
+    ///
+    /// Returns the raw words and checksum bytes as received from the
+    /// sensor, for callers debugging flaky bus wiring who want to inspect
+    /// them with [`verify_crc()`](crate::verify_crc) themselves. See
+    /// [`measure()`](#method.measure) for the checked equivalent.
+    pub async fn measure_raw<D: DelayNs>(
+        &mut self,
+        mode: PowerMode,
+        delay: &mut D,
+    ) -> Result<RawMeasurement, Error<I2C::Error>> {
+        let command = match mode {
+            PowerMode::NormalMode => Command::MeasureNormal,
+            PowerMode::LowPower => Command::MeasureLowPower,
+        };
+        self.send_command(command).await?;
+        delay.delay_us(M::max_conversion_time_us(mode)).await;
+        let mut buf = [0; 6];
+        self.i2c
+            .read(self.address, &mut buf)
+            .await
+            .map_err(Error::I2c)?;
+        Ok(RawMeasurement::from_bytes(&buf))
+    }
+}
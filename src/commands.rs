@@ -0,0 +1,37 @@
+//! I2C command words understood by the SHTC1 / SHTC3.
+
+/// A command that can be sent to the sensor over I2C.
+///
+/// Commands are 16-bit words, sent most-significant byte first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Command {
+    Wakeup,
+    Sleep,
+    SoftReset,
+    ReadIdReg,
+    MeasureNormal,
+    MeasureLowPower,
+    MeasureNormalClockStretching,
+    MeasureLowPowerClockStretching,
+}
+
+impl Command {
+    /// The raw 16-bit command word.
+    const fn word(self) -> u16 {
+        match self {
+            Command::Wakeup => 0x3517,
+            Command::Sleep => 0xB098,
+            Command::SoftReset => 0x805D,
+            Command::ReadIdReg => 0xEFC8,
+            Command::MeasureNormal => 0x7866,
+            Command::MeasureLowPower => 0x609C,
+            Command::MeasureNormalClockStretching => 0x7CA2,
+            Command::MeasureLowPowerClockStretching => 0x6458,
+        }
+    }
+
+    /// The command word as big-endian bytes, ready to write to the bus.
+    pub(crate) const fn to_be_bytes(self) -> [u8; 2] {
+        self.word().to_be_bytes()
+    }
+}
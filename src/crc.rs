@@ -0,0 +1,35 @@
+//! CRC8 checksum used to validate each 16-bit word returned by the sensor.
+
+/// Polynomial used by the SHTC1 / SHTC3 checksum: x^8 + x^5 + x^4 + 1.
+const CRC8_POLYNOMIAL: u8 = 0x31;
+
+/// Initial value of the CRC8 shift register.
+const CRC8_INIT: u8 = 0xFF;
+
+/// Compute the CRC8 checksum for a 16-bit word, as transmitted over I2C.
+///
+/// Uses polynomial `0x31`, initial value `0xFF`, no input or output
+/// reflection and no final XOR, as specified in the datasheet.
+fn crc8(data: &[u8; 2]) -> u8 {
+    let mut crc = CRC8_INIT;
+    for &byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ CRC8_POLYNOMIAL
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Check whether `crc` is the correct CRC8 checksum for `data`.
+///
+/// Useful for debugging flaky bus wiring: callers who retrieve raw words
+/// via [`measure_raw()`](crate::ShtCx::measure_raw) can verify each one
+/// themselves instead of relying on the driver's internal validation.
+pub fn verify_crc(data: &[u8; 2], crc: u8) -> bool {
+    crc8(data) == crc
+}
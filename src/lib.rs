@@ -0,0 +1,258 @@
+//! A platform agnostic Rust driver for the Sensirion SHTC1 and SHTC3
+//! digital humidity and temperature sensors, based on the
+//! [`embedded-hal`](https://docs.rs/embedded-hal) traits.
+//!
+//! ## The Device
+//!
+//! The Sensirion SHTC1 and SHTC3 are digital humidity and temperature
+//! sensors designed especially for battery-driven, high-volume consumer
+//! electronics applications. Both sensors are available in DFN packages
+//! and communicate over a standard I2C bus.
+//!
+//! - [SHTC1 datasheet](https://www.sensirion.com/file/datasheet_shtc1)
+//! - [SHTC3 datasheet](https://www.sensirion.com/file/datasheet_shtc3)
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use linux_embedded_hal::{Delay, I2cdev};
+//! use shtcx::{shtc3, PowerMode};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut sht = shtc3(dev);
+//! let mut delay = Delay;
+//!
+//! let measurement = sht.measure(PowerMode::NormalMode, &mut delay).unwrap();
+//! println!("{:?}", measurement);
+//! ```
+
+#![deny(unsafe_code)]
+#![cfg_attr(not(test), no_std)]
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::I2c;
+
+#[cfg(feature = "async")]
+mod asynch;
+mod commands;
+mod crc;
+mod measurement;
+mod model;
+
+#[cfg(feature = "async")]
+pub use crate::asynch::{shtc1_async, shtc3_async, AsyncShtCx};
+pub use crate::crc::verify_crc;
+pub use crate::measurement::{Humidity, Measurement, RawMeasurement, Temperature};
+pub use crate::model::{Shtc1, Shtc3};
+
+use crate::commands::Command;
+use crate::model::ShtCxModel;
+
+/// The I2C address used by the SHTC1 and SHTC3.
+const I2C_ADDRESS: u8 = 0x70;
+
+/// The power mode used for a measurement.
+///
+/// Normal mode takes a longer conversion time but yields the
+/// highest repeatability. Low power mode trades some repeatability for a
+/// much shorter, lower-energy conversion, which is useful for
+/// battery-driven applications that sample infrequently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerMode {
+    /// Normal measurement mode.
+    NormalMode,
+    /// Low power measurement mode.
+    LowPower,
+}
+
+/// Driver errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<E: embedded_hal::i2c::Error> {
+    /// I2C bus error.
+    I2c(E),
+    /// A received word failed its CRC8 checksum.
+    Crc,
+}
+
+/// Driver for the Sensirion SHTC1 / SHTC3 humidity and temperature sensors.
+///
+/// Use the [`shtc1()`](fn.shtc1.html) or [`shtc3()`](fn.shtc3.html)
+/// constructor functions to create an instance for your sensor variant.
+#[derive(Debug)]
+pub struct ShtCx<I2C, M> {
+    i2c: I2C,
+    address: u8,
+    _model: core::marker::PhantomData<M>,
+}
+
+/// Create a new driver instance for the SHTC1.
+pub fn shtc1<I2C>(i2c: I2C) -> ShtCx<I2C, Shtc1> {
+    ShtCx {
+        i2c,
+        address: I2C_ADDRESS,
+        _model: core::marker::PhantomData,
+    }
+}
+
+/// Create a new driver instance for the SHTC3.
+pub fn shtc3<I2C>(i2c: I2C) -> ShtCx<I2C, Shtc3> {
+    ShtCx {
+        i2c,
+        address: I2C_ADDRESS,
+        _model: core::marker::PhantomData,
+    }
+}
+
+impl<I2C, M> ShtCx<I2C, M>
+where
+    I2C: I2c,
+    M: ShtCxModel,
+{
+    /// Release the underlying I2C bus instance.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    fn send_command(&mut self, command: Command) -> Result<(), Error<I2C::Error>> {
+        self.i2c
+            .write(self.address, &command.to_be_bytes())
+            .map_err(Error::I2c)
+    }
+
+    /// Wake up the sensor from sleep mode.
+    ///
+    /// The SHTC3 automatically goes to sleep after each measurement. This
+    /// command must be sent (and the [recommended wakeup
+    /// time](https://www.sensirion.com/file/datasheet_shtc3) observed)
+    /// before a new measurement can be taken.
+    pub fn wakeup<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::Wakeup)?;
+        delay.delay_us(240);
+        Ok(())
+    }
+
+    /// Put the sensor into sleep mode to save power between measurements.
+    pub fn sleep(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::Sleep)
+    }
+
+    /// Perform a soft reset of the sensor.
+    pub fn reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::SoftReset)?;
+        delay.delay_us(240);
+        Ok(())
+    }
+
+    /// Read the device identifier register.
+    pub fn device_identifier(&mut self) -> Result<u16, Error<I2C::Error>> {
+        let mut buf = [0; 3];
+        self.i2c
+            .write_read(self.address, &Command::ReadIdReg.to_be_bytes(), &mut buf)
+            .map_err(Error::I2c)?;
+        let word = [buf[0], buf[1]];
+        if !verify_crc(&word, buf[2]) {
+            return Err(Error::Crc);
+        }
+        Ok(u16::from_be_bytes(word))
+    }
+
+    /// Perform a single temperature and humidity measurement.
+    ///
+    /// This blocks inside `delay` for the conversion time required by the
+    /// given [`PowerMode`](enum.PowerMode.html) (roughly 12 ms in normal
+    /// mode, 0.8 ms in low power mode on the SHTC3).
+    pub fn measure<D: DelayNs>(
+        &mut self,
+        mode: PowerMode,
+        delay: &mut D,
+    ) -> Result<Measurement, Error<I2C::Error>> {
+        self.start_measurement(mode)?;
+        delay.delay_us(M::max_conversion_time_us(mode));
+        self.read_measurement()
+    }
+
+    /// Perform a single temperature and humidity measurement, without
+    /// validating the CRC8 checksums.
+    ///
+    /// Returns the raw words and checksum bytes as received from the
+    /// sensor, for callers debugging flaky bus wiring who want to inspect
+    /// them with [`verify_crc()`](fn.verify_crc.html) themselves. See
+    /// [`measure()`](#method.measure) for the checked equivalent.
+    pub fn measure_raw<D: DelayNs>(
+        &mut self,
+        mode: PowerMode,
+        delay: &mut D,
+    ) -> Result<RawMeasurement, Error<I2C::Error>> {
+        self.start_measurement(mode)?;
+        delay.delay_us(M::max_conversion_time_us(mode));
+        self.read_measurement_raw()
+    }
+
+    /// Issue a measurement command without waiting for the result.
+    ///
+    /// This is the first half of a non-blocking, split measurement: send
+    /// the command, then wait at least
+    /// [`max_conversion_time_us(mode)`](#method.max_conversion_time_us)
+    /// using a timer of your choice, then call
+    /// [`read_measurement()`](#method.read_measurement) to retrieve the
+    /// result. This lets callers that can't afford to block inside a
+    /// blocking `delay` implementation (e.g. an async executor) drive the
+    /// wait themselves instead.
+    pub fn start_measurement(&mut self, mode: PowerMode) -> Result<(), Error<I2C::Error>> {
+        let command = match mode {
+            PowerMode::NormalMode => Command::MeasureNormal,
+            PowerMode::LowPower => Command::MeasureLowPower,
+        };
+        self.send_command(command)
+    }
+
+    /// Read back the result of a measurement previously started with
+    /// [`start_measurement()`](#method.start_measurement).
+    ///
+    /// The caller is responsible for having waited at least
+    /// [`max_conversion_time_us(mode)`](#method.max_conversion_time_us)
+    /// since the call to `start_measurement`; this method performs no
+    /// delay of its own. Each word's CRC8 checksum is validated, returning
+    /// [`Error::Crc`](enum.Error.html#variant.Crc) on mismatch.
+    pub fn read_measurement(&mut self) -> Result<Measurement, Error<I2C::Error>> {
+        self.read_measurement_raw()?.validate().ok_or(Error::Crc)
+    }
+
+    /// Read back the result of a measurement without validating the CRC8
+    /// checksums, for debugging a flaky bus.
+    ///
+    /// See [`read_measurement()`](#method.read_measurement) for the
+    /// checked equivalent.
+    pub fn read_measurement_raw(&mut self) -> Result<RawMeasurement, Error<I2C::Error>> {
+        let mut buf = [0; 6];
+        self.i2c.read(self.address, &mut buf).map_err(Error::I2c)?;
+        Ok(RawMeasurement::from_bytes(&buf))
+    }
+
+    /// Maximum conversion time for the given [`PowerMode`](enum.PowerMode.html),
+    /// in microseconds, as specified in the datasheet for this sensor
+    /// variant.
+    pub fn max_conversion_time_us(&self, mode: PowerMode) -> u32 {
+        M::max_conversion_time_us(mode)
+    }
+
+    /// Perform a measurement using the sensor's clock-stretching command.
+    ///
+    /// Instead of returning immediately and requiring the host to wait out
+    /// the conversion time, the sensor holds the I2C clock line (SCL) low
+    /// until the result is ready. This blocks the read at the I2C
+    /// peripheral level rather than via a fixed host-side delay, so no
+    /// [`DelayNs`](embedded_hal::delay::DelayNs) is needed here -- but it only works
+    /// if your I2C HAL supports clock stretching.
+    pub fn measure_clock_stretching(
+        &mut self,
+        mode: PowerMode,
+    ) -> Result<Measurement, Error<I2C::Error>> {
+        let command = match mode {
+            PowerMode::NormalMode => Command::MeasureNormalClockStretching,
+            PowerMode::LowPower => Command::MeasureLowPowerClockStretching,
+        };
+        self.send_command(command)?;
+        self.read_measurement()
+    }
+}
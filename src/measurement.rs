@@ -0,0 +1,146 @@
+//! Measurement result types.
+
+use crate::crc::verify_crc;
+
+/// A temperature reading, stored internally as milli-degrees Celsius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Temperature {
+    milli_degrees_celsius: i32,
+}
+
+impl Temperature {
+    /// Create a `Temperature` from the raw 16-bit sensor word.
+    pub(crate) fn from_raw(raw: u16) -> Self {
+        // Datasheet: T = -45 + 175 * raw / 2^16
+        let milli_degrees_celsius = -45_000 + (175_000 * i64::from(raw) / 65536) as i32;
+        Temperature {
+            milli_degrees_celsius,
+        }
+    }
+
+    /// The temperature in thousandths of a degree Celsius.
+    pub fn as_millidegrees_celsius(&self) -> i32 {
+        self.milli_degrees_celsius
+    }
+
+    /// The temperature in degrees Celsius.
+    pub fn as_degrees_celsius(&self) -> f32 {
+        self.milli_degrees_celsius as f32 / 1000.0
+    }
+}
+
+/// A relative humidity reading, stored internally as milli-percent RH.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Humidity {
+    milli_percent_rh: i32,
+}
+
+impl Humidity {
+    /// Create a `Humidity` from the raw 16-bit sensor word.
+    pub(crate) fn from_raw(raw: u16) -> Self {
+        // Datasheet: RH = 100 * raw / 2^16
+        let milli_percent_rh = (100_000 * i64::from(raw) / 65536) as i32;
+        Humidity { milli_percent_rh }
+    }
+
+    /// The relative humidity in thousandths of a percent.
+    pub fn as_millipercent(&self) -> i32 {
+        self.milli_percent_rh
+    }
+
+    /// The relative humidity as a percentage.
+    pub fn as_percent(&self) -> f32 {
+        self.milli_percent_rh as f32 / 1000.0
+    }
+}
+
+/// The result of a single measurement: a temperature and humidity pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    /// The measured temperature.
+    pub temperature: Temperature,
+    /// The measured relative humidity.
+    pub humidity: Humidity,
+}
+
+/// The raw words and checksums returned by a measurement, without CRC
+/// validation.
+///
+/// Retrieved via [`measure_raw()`](crate::ShtCx::measure_raw) so that a
+/// caller debugging flaky bus wiring can inspect the words and checksums
+/// themselves, e.g. with [`verify_crc()`](crate::verify_crc).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawMeasurement {
+    /// The raw 16-bit temperature word, as received from the sensor.
+    pub raw_temperature: u16,
+    /// The CRC8 checksum byte that followed the temperature word.
+    pub temperature_crc: u8,
+    /// The raw 16-bit humidity word, as received from the sensor.
+    pub raw_humidity: u16,
+    /// The CRC8 checksum byte that followed the humidity word.
+    pub humidity_crc: u8,
+}
+
+impl RawMeasurement {
+    /// Parse a `RawMeasurement` from the 6 raw bytes returned by the
+    /// sensor: `[temp_msb, temp_lsb, temp_crc, hum_msb, hum_lsb, hum_crc]`.
+    pub(crate) fn from_bytes(bytes: &[u8; 6]) -> Self {
+        RawMeasurement {
+            raw_temperature: u16::from_be_bytes([bytes[0], bytes[1]]),
+            temperature_crc: bytes[2],
+            raw_humidity: u16::from_be_bytes([bytes[3], bytes[4]]),
+            humidity_crc: bytes[5],
+        }
+    }
+
+    /// Convert to a [`Measurement`], without re-checking the checksums.
+    pub fn into_measurement(self) -> Measurement {
+        Measurement {
+            temperature: Temperature::from_raw(self.raw_temperature),
+            humidity: Humidity::from_raw(self.raw_humidity),
+        }
+    }
+
+    /// Validate both words' CRC8 checksums, converting to a [`Measurement`]
+    /// only if they match.
+    pub(crate) fn validate(self) -> Option<Measurement> {
+        let temperature_word = self.raw_temperature.to_be_bytes();
+        let humidity_word = self.raw_humidity.to_be_bytes();
+        if verify_crc(&temperature_word, self.temperature_crc)
+            && verify_crc(&humidity_word, self.humidity_crc)
+        {
+            Some(self.into_measurement())
+        } else {
+            None
+        }
+    }
+}
+
+impl Measurement {
+    /// Compute the dew point using the Magnus formula.
+    ///
+    /// This requires the `libm` feature, which provides the `exp`/`ln`
+    /// implementations needed for the calculation on `no_std` targets.
+    #[cfg(feature = "libm")]
+    pub fn dew_point(&self) -> f32 {
+        const B: f32 = 17.62;
+        const C: f32 = 243.12;
+        let t = self.temperature.as_degrees_celsius();
+        let rh = self.humidity.as_percent();
+        let gamma = libm::logf(rh / 100.0) + (B * t) / (C + t);
+        C * gamma / (B - gamma)
+    }
+
+    /// Compute the absolute humidity in g/m³.
+    ///
+    /// This requires the `libm` feature, which provides the `exp`/`ln`
+    /// implementations needed for the calculation on `no_std` targets.
+    #[cfg(feature = "libm")]
+    pub fn absolute_humidity(&self) -> f32 {
+        const B: f32 = 17.62;
+        const C: f32 = 243.12;
+        let t = self.temperature.as_degrees_celsius();
+        let rh = self.humidity.as_percent();
+        216.7 * (rh / 100.0 * 6.112 * libm::expf(B * t / (C + t)) / (273.15 + t))
+    }
+}
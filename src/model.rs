@@ -0,0 +1,53 @@
+//! Marker types distinguishing the SHTC1 and SHTC3 sensor variants.
+//!
+//! Both chips share the same command set and wire protocol, but differ
+//! slightly in their maximum conversion times. The `ShtCx<I2C, M>` driver
+//! is generic over one of these marker types so that the correct timing
+//! can be selected at compile time.
+
+use crate::PowerMode;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::Shtc1 {}
+    impl Sealed for super::Shtc3 {}
+}
+
+/// Sensor-specific constants, implemented for [`Shtc1`](struct.Shtc1.html)
+/// and [`Shtc3`](struct.Shtc3.html).
+pub trait ShtCxModel: private::Sealed {
+    /// Maximum conversion time in normal power mode, in microseconds.
+    const CONVERSION_TIME_NORMAL_US: u32;
+    /// Maximum conversion time in low power mode, in microseconds.
+    const CONVERSION_TIME_LOWPOWER_US: u32;
+
+    /// Maximum conversion time for the given [`PowerMode`](enum.PowerMode.html), in microseconds.
+    fn max_conversion_time_us(mode: PowerMode) -> u32 {
+        match mode {
+            PowerMode::NormalMode => Self::CONVERSION_TIME_NORMAL_US,
+            PowerMode::LowPower => Self::CONVERSION_TIME_LOWPOWER_US,
+        }
+    }
+}
+
+/// Marker type for the SHTC1.
+#[derive(Debug)]
+pub struct Shtc1;
+
+/// Marker type for the SHTC3.
+#[derive(Debug)]
+pub struct Shtc3;
+
+impl ShtCxModel for Shtc1 {
+    /// Datasheet: 14.4 ms max in normal mode.
+    const CONVERSION_TIME_NORMAL_US: u32 = 14_400;
+    /// Datasheet: 0.94 ms max in low power mode.
+    const CONVERSION_TIME_LOWPOWER_US: u32 = 940;
+}
+
+impl ShtCxModel for Shtc3 {
+    /// Datasheet: 12.1 ms max in normal mode.
+    const CONVERSION_TIME_NORMAL_US: u32 = 12_100;
+    /// Datasheet: 0.8 ms max in low power mode.
+    const CONVERSION_TIME_LOWPOWER_US: u32 = 800;
+}